@@ -0,0 +1,28 @@
+//! Re-exports the handful of alloc-backed types the codec needs, sourced
+//! from `std` when it's available and from `alloc` otherwise, so the rest
+//! of the crate can stay agnostic of which one is in play.
+
+#[cfg(feature = "std")]
+pub(crate) use std::string::String;
+#[cfg(not(feature = "std"))]
+pub(crate) use alloc::string::String;
+
+#[cfg(feature = "std")]
+pub(crate) use std::vec::Vec;
+#[cfg(not(feature = "std"))]
+pub(crate) use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+pub(crate) use std::boxed::Box;
+#[cfg(not(feature = "std"))]
+pub(crate) use alloc::boxed::Box;
+
+#[cfg(feature = "std")]
+pub(crate) use std::string::FromUtf8Error;
+#[cfg(not(feature = "std"))]
+pub(crate) use alloc::string::FromUtf8Error;
+
+#[cfg(feature = "std")]
+pub(crate) use std::string::ToString;
+#[cfg(not(feature = "std"))]
+pub(crate) use alloc::string::ToString;
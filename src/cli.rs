@@ -0,0 +1,229 @@
+//! Command-line argument handling for the `mindustry_parser` binary.
+
+use std::collections::VecDeque;
+use std::io;
+use std::path;
+
+use crate::{AddressedValue, Settings};
+use crate::text::decode_hex;
+
+/// Wraps a message as an `io::Error` of kind `InvalidInput`, for malformed
+/// CLI arguments that should produce a clean diagnostic via `main`'s
+/// `Err(err) => eprintln!("Error: {err}")` instead of a panic/backtrace.
+#[inline]
+fn invalid_input(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+#[inline]
+fn parse_bool(value: String) -> bool {
+    let lower = value.to_lowercase();
+
+    match lower.as_str() {
+        "0" | "false" | "f" | "nil" | "no" | "off" | "inactive" => false,
+        "1" | "true" | "t" | "yes" | "on" | "active" => true,
+        _ => panic!("Bad bool: value")
+    }
+}
+
+enum Operation {
+    Read,
+    Write,
+    Export,
+    Import,
+    WriteFile,
+    ReadFile
+}
+
+fn export_text(settings: &Settings, path: &str) -> io::Result<()> {
+    std::fs::write(path, settings.to_text())
+}
+
+fn import_text(path: &str) -> io::Result<Settings> {
+    let text = std::fs::read_to_string(path)?;
+    Ok(Settings::from_text(&text)?)
+}
+
+/// Parses `std::env::args()` and runs the requested read/write operations.
+pub fn run() -> io::Result<()> {
+    let mut args: VecDeque<String> = std::env::args().collect();
+    args.pop_front(); // Ditch the command name
+
+    let file_path_string = args.pop_front();
+
+    if file_path_string.is_none() {
+        println!("SYNTAX:");
+        println!("mindustry_parser path/to/settings.bin --read <key> ...");
+        println!("  Print the value and byte address of <key>");
+        println!();
+        println!("mindustry_parser path/to/settings.bin --write <key> <value> ...");
+        println!("  Set <key> to <value>");
+        println!();
+        println!("mindustry_parser path/to/settings.bin --show-all");
+        println!("  Prints all keys, values, and addresses found in the file");
+        println!();
+        println!("mindustry_parser path/to/settings.bin --pretend --write <key> <value>");
+        println!("  The --pretend flag modifies the settings in memory only, and does not modify the file on disk");
+        println!();
+        println!("mindustry_parser path/to/settings.bin --export <path.txt>");
+        println!("  Dumps every key/type/value as a `key<TAB>type<TAB>value` line for hand-editing");
+        println!();
+        println!("mindustry_parser path/to/settings.bin --import <path.txt>");
+        println!("  Replaces the in-memory settings with the entries parsed from <path.txt>");
+        println!();
+        println!("mindustry_parser path/to/settings.bin --write <key> <hexstring>");
+        println!("  Sets a binary <key> to the bytes decoded from <hexstring> (e.g. DEADBEEF)");
+        println!();
+        println!("mindustry_parser path/to/settings.bin --write-file <key> <path>");
+        println!("  Sets a binary <key> to the raw bytes read from <path>");
+        println!();
+        println!("mindustry_parser path/to/settings.bin --read-file <key> <path>");
+        println!("  Dumps a binary <key>'s raw bytes to <path>");
+        println!();
+        println!("mindustry_parser path/to/settings.bin --backup --write <key> <value>");
+        println!("  The --backup flag keeps the pre-write file contents alongside as a <path>.bak");
+        println!();
+        println!("The above argument groups can be used multiple times in a sequence, as desired.");
+        println!("  -r => alias for --read");
+        println!("  -w => alias for --write");
+        println!();
+        println!("Valid boolean values for \"true\": 1 true t yes on active");
+        println!("Valid boolean values for \"false\": 0 false f nil no off inactive");
+        std::process::exit(0);
+    }
+
+    let file_path_string = file_path_string.unwrap();
+
+    let file_path = path::Path::new(&file_path_string).canonicalize()?;
+
+    let mut show_all = false;
+    let mut pretend = false;
+    let mut backup = false;
+    let mut is_dirty = false;
+
+    for arg in &args {
+        let lower = arg.to_lowercase();
+        match lower.as_str() {
+            "--show-all" => { show_all = true; },
+            "--pretend" => { pretend = true; },
+            "--backup" => { backup = true; },
+            _ => { }
+        }
+    }
+
+    let mut settings = Settings::load_file(&file_path)?;
+
+    if show_all {
+        for (key, item) in settings.iter() {
+            println!("{key}={item}");
+        }
+    }
+
+    let mut op: Option<Operation> = None;
+    let mut op_key: Option<String> = None;
+
+    for arg in args {
+        if op.is_none() {
+            let lower = arg.to_lowercase();
+            match lower.as_str() {
+                "--read" | "-r" => { op = Some(Operation::Read); },
+                "--write" | "-w" => { op = Some(Operation::Write); },
+                "--export" => { op = Some(Operation::Export); },
+                "--import" => { op = Some(Operation::Import); },
+                "--write-file" => { op = Some(Operation::WriteFile); },
+                "--read-file" => { op = Some(Operation::ReadFile); },
+                "--show-all" | "--pretend" | "--backup" => { continue; },
+                _ => panic!("Unkown operation: {arg}")
+            };
+        }
+        else if op_key.is_none() {
+            match op {
+                Some(Operation::Read) => {
+                    let found_item = settings.get(&arg)
+                        .unwrap_or_else(|| panic!("Key not found: {arg}"));
+                    print!("{arg}={found_item},");
+                    op = None;
+                },
+                Some(Operation::Write) => {
+                    if settings.get(&arg).is_none() {
+                        panic!("Key not found: {arg}");
+                    }
+                    op_key = Some(arg);
+                },
+                Some(Operation::WriteFile) => {
+                    if settings.get(&arg).is_none() {
+                        panic!("Key not found: {arg}");
+                    }
+                    op_key = Some(arg);
+                },
+                Some(Operation::ReadFile) => {
+                    if settings.get(&arg).is_none() {
+                        panic!("Key not found: {arg}");
+                    }
+                    op_key = Some(arg);
+                },
+                Some(Operation::Export) => {
+                    export_text(&settings, &arg)?;
+                    println!("Exported to {arg}");
+                    op = None;
+                },
+                Some(Operation::Import) => {
+                    settings = import_text(&arg)?;
+                    is_dirty = true;
+                    op = None;
+                },
+                None => unreachable!()
+            }
+        }
+        else if matches!(op, Some(Operation::ReadFile)) {
+            let key = op_key.take().unwrap();
+            let found_item = settings.get(&key).unwrap();
+            match &found_item.value {
+                AddressedValue::Binary(bytes) => std::fs::write(&arg, bytes.as_ref().unwrap().as_slice())?,
+                _ => return Err(invalid_input(format!("--read-file only works on binary keys: {key}")))
+            }
+            println!("Dumped {key} to {arg}");
+            op = None;
+        }
+        else {
+            // It only gets this far during a write or write-file op
+            let key = op_key.take().unwrap();
+            let is_write_file = matches!(op, Some(Operation::WriteFile));
+            let found_item = settings.get_mut(&key).unwrap();
+            let value = &mut found_item.value;
+
+            if is_write_file {
+                match value {
+                    AddressedValue::Binary(_) => *value = AddressedValue::Binary(Some(Box::new(std::fs::read(&arg)?))),
+                    _ => return Err(invalid_input(format!("--write-file only works on binary keys: {key}")))
+                }
+            }
+            else {
+                match value {
+                    AddressedValue::Bool(_) => *value = AddressedValue::Bool(parse_bool(arg)),
+                    AddressedValue::Int(_) => *value = AddressedValue::Int(arg.parse::<u32>().expect("Bad positive integer: {arg}")),
+                    AddressedValue::Long(_) => *value = AddressedValue::Long(arg.parse::<u64>().expect("Bad positive integer: {arg}")),
+                    AddressedValue::Float(_) => *value = AddressedValue::Float(arg.parse::<f32>().expect("Bad floating point: {arg}")),
+                    AddressedValue::String(_) => *value = AddressedValue::String(Some(Box::new(arg))),
+                    AddressedValue::Binary(_) => *value = AddressedValue::Binary(Some(Box::new(
+                        decode_hex(&arg).ok_or_else(|| invalid_input(format!("Bad hex string: {arg}")))?
+                    )))
+                }
+            }
+
+            op = None;
+            op_key = None;
+            is_dirty = true;
+        }
+    }
+
+    println!();
+
+    if is_dirty && !pretend {
+        settings.save_file(&file_path, backup)?;
+
+        println!("The file has been modified.");
+    }
+
+    Ok(())
+}
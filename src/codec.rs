@@ -0,0 +1,350 @@
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(not(feature = "std"))]
+use alloc::collections::BTreeMap;
+
+use crate::alloc_prelude::{Box, String, Vec};
+use crate::error::SettingsError;
+use crate::reader::Reader;
+use crate::value::{AddressedValue, SettingsItem};
+
+/// Keyed by key name. `std` builds use a `HashMap` for O(1) lookups; bare
+/// `alloc` targets fall back to a `BTreeMap` since `std::collections::HashMap`
+/// isn't available there.
+#[cfg(feature = "std")]
+type Map = HashMap<String, SettingsItem>;
+#[cfg(not(feature = "std"))]
+type Map = BTreeMap<String, SettingsItem>;
+
+/// The decoded contents of a `settings.bin` file.
+#[derive(Debug)]
+pub struct Settings {
+    items: Map
+}
+
+impl Settings {
+    /// Decodes a `settings.bin` byte buffer into its keyed entries.
+    pub fn parse(bytes: &[u8]) -> Result<Settings, SettingsError> {
+        let mut reader = Reader::new(bytes);
+
+        let entry_count = reader.read_u32()? as usize;
+
+        let mut items: Map = Map::new();
+
+        for _i in 0..entry_count {
+            let key = read_key(&mut reader)?;
+            let type_id_offset = reader.position();
+            let type_id = reader.read_u8()?;
+            let item = match type_id {
+                0 => read_bool(&mut reader)?,
+                1 => read_u32(&mut reader)?,
+                2 => read_u64(&mut reader)?,
+                3 => read_f32(&mut reader)?,
+                4 => read_string(&mut reader)?,
+                5 => read_binary(&mut reader)?,
+                _ => return Err(SettingsError::UnknownTypeId { offset: type_id_offset, type_id })
+            };
+
+            items.insert(key, item);
+        }
+
+        Ok(Settings { items })
+    }
+
+    /// Encodes the current entries back into `settings.bin`'s byte format.
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut out_buffer: Vec<u8> = Vec::new();
+
+        let item_count = self.items.len() as u32;
+        for b in item_count.to_be_bytes() {
+            out_buffer.push(b);
+        }
+
+        for (key, item) in &self.items {
+            write_string_to_buffer(key, &mut out_buffer);
+
+            match &item.value {
+                AddressedValue::Bool(value) => { out_buffer.push(0); out_buffer.push(match value { true => 1u8, _ => 0u8 }); },
+                AddressedValue::Int(value) => { out_buffer.push(1); out_buffer.extend(value.to_be_bytes()); },
+                AddressedValue::Long(value) => { out_buffer.push(2); out_buffer.extend(value.to_be_bytes()); },
+                AddressedValue::Float(value) => { out_buffer.push(3); out_buffer.extend(value.to_be_bytes()); },
+                AddressedValue::String(value) => {
+                    out_buffer.push(4);
+                    write_string_to_buffer(value.as_ref().unwrap(), &mut out_buffer);
+                },
+                AddressedValue::Binary(value) => {
+                    out_buffer.push(5);
+                    let bytes = value.as_ref().unwrap();
+                    let len = bytes.len() as u32;
+                    for b in len.to_be_bytes() {
+                        out_buffer.push(b);
+                    }
+                    out_buffer.extend(bytes.iter().copied());
+                }
+            }
+        }
+
+        out_buffer
+    }
+
+    /// Looks up a decoded entry by key.
+    pub fn get(&self, key: &str) -> Option<&SettingsItem> {
+        self.items.get(key)
+    }
+
+    /// Looks up a decoded entry by key, for in-place editing.
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut SettingsItem> {
+        self.items.get_mut(key)
+    }
+
+    /// Iterates over every decoded key/value entry.
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &SettingsItem)> {
+        self.items.iter()
+    }
+
+    /// Dumps every entry as `key<TAB>type<TAB>value` lines for hand-editing.
+    ///
+    /// `bin` values are hex-encoded; the result can be fed straight back into
+    /// [`Settings::from_text`].
+    pub fn to_text(&self) -> String {
+        let mut out = String::new();
+
+        for (key, item) in &self.items {
+            out.push_str(key);
+            out.push('\t');
+            out.push_str(crate::text::tag_for(&item.value));
+            out.push('\t');
+            out.push_str(&crate::text::format_value(&item.value));
+            out.push('\n');
+        }
+
+        out
+    }
+
+    /// Parses the `key<TAB>type<TAB>value` grammar produced by
+    /// [`Settings::to_text`] back into a full settings set.
+    ///
+    /// Imported entries have no file offset, so their `address` is `0`.
+    pub fn from_text(text: &str) -> Result<Settings, SettingsError> {
+        let mut items: Map = Map::new();
+
+        for (line_no, line) in text.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+
+            let line_no = line_no + 1;
+            let mut fields = line.splitn(3, '\t');
+
+            let key = fields.next().ok_or(SettingsError::InvalidTextLine { line: line_no })?;
+            let tag = fields.next().ok_or(SettingsError::InvalidTextLine { line: line_no })?;
+            let raw_value = fields.next().ok_or(SettingsError::InvalidTextLine { line: line_no })?;
+
+            let value = crate::text::parse_value(tag, raw_value, line_no)?;
+
+            items.insert(String::from(key), SettingsItem { address: 0, value });
+        }
+
+        Ok(Settings { items })
+    }
+}
+
+#[cfg(feature = "std")]
+impl Settings {
+    /// Reads and decodes a `settings.bin` file from disk.
+    pub fn load_file<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<Settings> {
+        use std::io::Read;
+
+        let mut buffer = Vec::new();
+        let mut file = std::fs::File::open(path)?;
+        file.read_to_end(&mut buffer)?;
+
+        Ok(Settings::parse(&buffer)?)
+    }
+
+    /// Encodes the current entries and atomically replaces an existing
+    /// `settings.bin` file with the result.
+    ///
+    /// The new contents are written to a sibling `.tmp` file, `fsync`ed, then
+    /// renamed over `path`, so a process interrupted mid-write leaves the
+    /// original file untouched rather than truncated. When `backup` is set,
+    /// the pre-write contents are preserved alongside as a sibling `.bak`
+    /// file before the rename.
+    pub fn save_file<P: AsRef<std::path::Path>>(&self, path: P, backup: bool) -> std::io::Result<()> {
+        use std::io::Write;
+
+        let path = path.as_ref();
+        let bytes = self.serialize();
+
+        let tmp_path = path.with_extension("tmp");
+        let mut tmp_file = std::fs::File::create(&tmp_path)?;
+        tmp_file.write_all(&bytes)?;
+        tmp_file.sync_all()?;
+        drop(tmp_file);
+
+        if backup {
+            std::fs::copy(path, path.with_extension("bak"))?;
+        }
+
+        std::fs::rename(&tmp_path, path)?;
+
+        Ok(())
+    }
+}
+
+#[inline]
+fn read_bool(reader: &mut Reader) -> Result<SettingsItem, SettingsError> {
+    let start_offset = reader.position();
+    let byte = reader.read_u8()?;
+
+    let value = match byte {
+        0 => false,
+        1 => true,
+        _ => return Err(SettingsError::InvalidBool { offset: start_offset, byte })
+    };
+
+    Ok(SettingsItem {
+        address: start_offset,
+        value: AddressedValue::Bool(value)
+    })
+}
+
+#[inline]
+fn read_u32(reader: &mut Reader) -> Result<SettingsItem, SettingsError> {
+    let start_offset = reader.position();
+
+    Ok(SettingsItem {
+        address: start_offset,
+        value: AddressedValue::Int(reader.read_u32()?)
+    })
+}
+
+#[inline]
+fn read_u64(reader: &mut Reader) -> Result<SettingsItem, SettingsError> {
+    let start_offset = reader.position();
+
+    Ok(SettingsItem {
+        address: start_offset,
+        value: AddressedValue::Long(reader.read_u64()?)
+    })
+}
+
+#[inline]
+fn read_f32(reader: &mut Reader) -> Result<SettingsItem, SettingsError> {
+    let start_offset = reader.position();
+
+    Ok(SettingsItem {
+        address: start_offset,
+        value: AddressedValue::Float(reader.read_f32()?)
+    })
+}
+
+#[inline]
+fn read_binary(reader: &mut Reader) -> Result<SettingsItem, SettingsError> {
+    let len = reader.read_u32()? as usize;
+    let start_offset = reader.position();
+    let bytes = reader.read_bytes(len)?;
+
+    Ok(SettingsItem {
+        address: start_offset,
+        value: AddressedValue::Binary(Some(Box::new(bytes.to_vec())))
+    })
+}
+
+#[inline]
+fn read_key(reader: &mut Reader) -> Result<String, SettingsError> {
+    let len = reader.read_u16()? as usize;
+    let start_offset = reader.position();
+    let bytes = reader.read_bytes(len)?;
+
+    String::from_utf8(bytes.to_vec())
+        .map_err(|source| SettingsError::InvalidUtf8 { offset: start_offset, source })
+}
+
+#[inline]
+fn read_string(reader: &mut Reader) -> Result<SettingsItem, SettingsError> {
+    let len = reader.read_u16()? as usize;
+    let start_offset = reader.position();
+    let bytes = reader.read_bytes(len)?;
+
+    let series = String::from_utf8(bytes.to_vec())
+        .map_err(|source| SettingsError::InvalidUtf8 { offset: start_offset, source })?;
+
+    Ok(SettingsItem {
+        address: start_offset,
+        value: AddressedValue::String(Some(Box::new(series)))
+    })
+}
+
+#[inline]
+fn write_string_to_buffer(value: &str, out_buffer: &mut Vec<u8>) {
+    let key_len = value.len() as u16;
+    for b in key_len.to_be_bytes() {
+        out_buffer.push(b);
+    }
+
+    out_buffer.extend(value.as_bytes().iter().copied());
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    /// Builds a small settings set covering every value type, including a
+    /// string with embedded `\r`/`\n`/`\\`, the one type that can be
+    /// mis-split by the one-line-per-entry text dump.
+    fn sample_settings() -> Settings {
+        let mut items: Map = Map::new();
+
+        items.insert(String::from("enabled"), SettingsItem { address: 0, value: AddressedValue::Bool(true) });
+        items.insert(String::from("count"), SettingsItem { address: 0, value: AddressedValue::Int(42) });
+        items.insert(String::from("total"), SettingsItem { address: 0, value: AddressedValue::Long(u64::MAX) });
+        items.insert(String::from("ratio"), SettingsItem { address: 0, value: AddressedValue::Float(1.5) });
+        items.insert(String::from("blob"), SettingsItem { address: 0, value: AddressedValue::Binary(Some(Box::new(vec![0xDE, 0xAD, 0xBE, 0xEF]))) });
+        items.insert(String::from("tricky"), SettingsItem {
+            address: 0,
+            value: AddressedValue::String(Some(Box::new(String::from("abc\rdef\nghi\\jkl"))))
+        });
+
+        Settings { items }
+    }
+
+    #[test]
+    fn text_round_trip_preserves_embedded_cr_lf() {
+        let original = sample_settings();
+
+        let dumped = original.to_text();
+        let restored = Settings::from_text(&dumped).expect("export of valid settings should re-import");
+
+        // `items` is a HashMap under the `std` feature, so entries may come
+        // back in a different order; compare the maps themselves rather
+        // than `serialize()`'s byte order, which would be flaky.
+        assert_eq!(original.items, restored.items);
+    }
+
+    #[test]
+    fn parse_truncated_buffer_returns_unexpected_eof() {
+        // Claims one entry but the buffer ends right after the count, so
+        // the key's length prefix can't be read.
+        let bytes: &[u8] = &[0, 0, 0, 1];
+
+        let result = Settings::parse(bytes);
+
+        assert!(matches!(result, Err(SettingsError::UnexpectedEof { offset: 4 })));
+    }
+
+    #[test]
+    fn parse_truncated_mid_value_returns_unexpected_eof() {
+        // A complete key and type tag (int, id 1), but the 4-byte payload
+        // is cut short after 2 bytes.
+        let mut bytes = vec![0, 0, 0, 1];
+        bytes.extend([0, 3]); // key length
+        bytes.extend(b"foo");
+        bytes.push(1); // type id: Int
+        bytes.extend([0xDE, 0xAD]); // only 2 of the 4 payload bytes
+
+        let result = Settings::parse(&bytes);
+
+        assert!(matches!(result, Err(SettingsError::UnexpectedEof { offset: 10 })));
+    }
+}
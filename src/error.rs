@@ -0,0 +1,69 @@
+use core::fmt;
+
+use crate::alloc_prelude::{FromUtf8Error, String};
+
+/// Everything that can go wrong while decoding a `settings.bin` buffer or a
+/// `--export`/`--import` text dump.
+///
+/// The binary variants carry the byte offset at which the failure occurred;
+/// the text variants carry the 1-based line number instead, so callers can
+/// point a user at the exact spot in a truncated, corrupt, or malformed file.
+#[derive(Debug)]
+pub enum SettingsError {
+    /// The buffer ran out of bytes before a value could be fully read.
+    UnexpectedEof { offset: usize },
+    /// An entry's type tag didn't match any known `AddressedValue` variant.
+    UnknownTypeId { offset: usize, type_id: u8 },
+    /// A boolean entry held a byte other than `0` or `1`.
+    InvalidBool { offset: usize, byte: u8 },
+    /// A string or key's bytes weren't valid UTF-8.
+    InvalidUtf8 { offset: usize, source: FromUtf8Error },
+    /// A text dump line didn't have the `key<TAB>type<TAB>value` shape.
+    InvalidTextLine { line: usize },
+    /// A text dump line's `type` field wasn't one of the known tags.
+    UnknownValueTag { line: usize, tag: String },
+    /// A text dump line's `value` field didn't parse for its declared type.
+    InvalidTextValue { line: usize },
+    /// A `bin`-tagged value wasn't a well-formed even-length hex string.
+    InvalidHex { line: usize }
+}
+
+impl fmt::Display for SettingsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SettingsError::UnexpectedEof { offset } =>
+                write!(f, "unexpectedly reached the end of the file at offset {offset:#X}"),
+            SettingsError::UnknownTypeId { offset, type_id } =>
+                write!(f, "unknown type id {type_id} at offset {offset:#X}"),
+            SettingsError::InvalidBool { offset, byte } =>
+                write!(f, "malformed boolean byte {byte:#X} at offset {offset:#X}"),
+            SettingsError::InvalidUtf8 { offset, source } =>
+                write!(f, "malformed UTF-8 string at offset {offset:#X}: {source}"),
+            SettingsError::InvalidTextLine { line } =>
+                write!(f, "line {line}: expected `key<TAB>type<TAB>value`"),
+            SettingsError::UnknownValueTag { line, tag } =>
+                write!(f, "line {line}: unknown value type tag \"{tag}\""),
+            SettingsError::InvalidTextValue { line } =>
+                write!(f, "line {line}: value didn't match its declared type"),
+            SettingsError::InvalidHex { line } =>
+                write!(f, "line {line}: expected an even-length hex string")
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SettingsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SettingsError::InvalidUtf8 { source, .. } => Some(source),
+            _ => None
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<SettingsError> for std::io::Error {
+    fn from(err: SettingsError) -> Self {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, err)
+    }
+}
@@ -0,0 +1,28 @@
+//! Reader and writer for Mindustry's `settings.bin` format.
+//!
+//! The `std`-gated [`Settings::load_file`]/[`Settings::save_file`] pair cover
+//! the common case of editing a file on disk; [`Settings::parse`] and
+//! [`Settings::serialize`] work on a bare `&[u8]`/`Vec<u8>` and only need
+//! `alloc`, so the codec can be embedded in `no_std` tooling.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+mod alloc_prelude;
+mod error;
+mod reader;
+mod text;
+mod value;
+mod codec;
+
+#[cfg(feature = "cli")]
+pub mod cli;
+
+pub use error::SettingsError;
+pub use value::{AddressedValue, SettingsItem};
+pub use codec::Settings;
+
+/// Shorthand for a parse result carrying a [`SettingsError`].
+pub type Result<T> = core::result::Result<T, SettingsError>;
@@ -0,0 +1,56 @@
+use crate::error::SettingsError;
+
+/// A borrowing cursor over a `settings.bin` byte buffer.
+///
+/// Every read bounds-checks against the remaining slice and returns
+/// [`SettingsError::UnexpectedEof`] instead of panicking, slicing directly
+/// out of the borrowed buffer rather than copying into an intermediate
+/// queue.
+pub(crate) struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Reader { data, pos: 0 }
+    }
+
+    /// The current byte offset into the buffer.
+    pub(crate) fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Borrows the next `len` bytes and advances past them.
+    pub(crate) fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], SettingsError> {
+        let start = self.pos;
+        let end = start.checked_add(len)
+            .filter(|&end| end <= self.data.len())
+            .ok_or(SettingsError::UnexpectedEof { offset: start })?;
+
+        let slice = &self.data[start..end];
+        self.pos = end;
+
+        Ok(slice)
+    }
+
+    pub(crate) fn read_u8(&mut self) -> Result<u8, SettingsError> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    pub(crate) fn read_u16(&mut self) -> Result<u16, SettingsError> {
+        Ok(u16::from_be_bytes(self.read_bytes(2)?.try_into().unwrap()))
+    }
+
+    pub(crate) fn read_u32(&mut self) -> Result<u32, SettingsError> {
+        Ok(u32::from_be_bytes(self.read_bytes(4)?.try_into().unwrap()))
+    }
+
+    pub(crate) fn read_u64(&mut self) -> Result<u64, SettingsError> {
+        Ok(u64::from_be_bytes(self.read_bytes(8)?.try_into().unwrap()))
+    }
+
+    pub(crate) fn read_f32(&mut self) -> Result<f32, SettingsError> {
+        Ok(f32::from_be_bytes(self.read_bytes(4)?.try_into().unwrap()))
+    }
+}
@@ -0,0 +1,140 @@
+use core::fmt::Write as _;
+
+use crate::alloc_prelude::{Box, String, ToString, Vec};
+use crate::error::SettingsError;
+use crate::value::AddressedValue;
+
+/// The `type` tag used for a value in the `--export`/`--import` text dump.
+pub(crate) fn tag_for(value: &AddressedValue) -> &'static str {
+    match value {
+        AddressedValue::Bool(_) => "bool",
+        AddressedValue::Int(_) => "int",
+        AddressedValue::Long(_) => "long",
+        AddressedValue::Float(_) => "float",
+        AddressedValue::String(_) => "str",
+        AddressedValue::Binary(_) => "bin"
+    }
+}
+
+/// Renders a value's `value` field for the text dump.
+pub(crate) fn format_value(value: &AddressedValue) -> String {
+    match value {
+        AddressedValue::Bool(v) => v.to_string(),
+        AddressedValue::Int(v) => v.to_string(),
+        AddressedValue::Long(v) => v.to_string(),
+        AddressedValue::Float(v) => v.to_string(),
+        AddressedValue::String(v) => escape_text(v.as_ref().unwrap()),
+        AddressedValue::Binary(v) => format_hex(v.as_ref().unwrap())
+    }
+}
+
+/// Parses a `type`/`value` pair back into an [`AddressedValue`].
+pub(crate) fn parse_value(tag: &str, raw: &str, line: usize) -> Result<AddressedValue, SettingsError> {
+    Ok(match tag {
+        "bool" => AddressedValue::Bool(match raw {
+            "true" => true,
+            "false" => false,
+            _ => return Err(SettingsError::InvalidTextValue { line })
+        }),
+        "int" => AddressedValue::Int(raw.parse().map_err(|_| SettingsError::InvalidTextValue { line })?),
+        "long" => AddressedValue::Long(raw.parse().map_err(|_| SettingsError::InvalidTextValue { line })?),
+        "float" => AddressedValue::Float(raw.parse().map_err(|_| SettingsError::InvalidTextValue { line })?),
+        "str" => AddressedValue::String(Some(Box::new(unescape_text(raw, line)?))),
+        "bin" => AddressedValue::Binary(Some(Box::new(parse_hex(raw, line)?))),
+        _ => return Err(SettingsError::UnknownValueTag { line, tag: String::from(tag) })
+    })
+}
+
+/// Backslash-escapes `\\`, `\n`, and `\r` in a string value so it can't
+/// introduce or drop a line break when written into the one-line-per-entry
+/// text dump.
+pub(crate) fn escape_text(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+
+    for ch in text.chars() {
+        match ch {
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            _ => out.push(ch)
+        }
+    }
+
+    out
+}
+
+/// Reverses [`escape_text`], rejecting an unsupported or dangling `\`-escape.
+pub(crate) fn unescape_text(text: &str, line: usize) -> Result<String, SettingsError> {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            out.push(ch);
+            continue;
+        }
+
+        match chars.next() {
+            Some('\\') => out.push('\\'),
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            _ => return Err(SettingsError::InvalidTextValue { line })
+        }
+    }
+
+    Ok(out)
+}
+
+/// Hex-encodes a byte slice as paired uppercase digits, e.g. `DEADBEEF`.
+pub(crate) fn format_hex(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        let _ = write!(out, "{byte:02X}");
+    }
+    out
+}
+
+/// Decodes a paired hex string (e.g. `DEADBEEF`) into bytes, or `None` if
+/// it isn't a well-formed even-length hex string.
+pub(crate) fn decode_hex(text: &str) -> Option<Vec<u8>> {
+    let text = text.as_bytes();
+
+    if !text.len().is_multiple_of(2) {
+        return None;
+    }
+
+    text.chunks_exact(2)
+        .map(|pair| {
+            let hi = (pair[0] as char).to_digit(16)?;
+            let lo = (pair[1] as char).to_digit(16)?;
+            Some(((hi << 4) | lo) as u8)
+        })
+        .collect()
+}
+
+/// Decodes a paired hex string back into bytes, for the text dump grammar.
+pub(crate) fn parse_hex(text: &str, line: usize) -> Result<Vec<u8>, SettingsError> {
+    decode_hex(text).ok_or(SettingsError::InvalidHex { line })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_hex_accepts_well_formed_input() {
+        assert_eq!(decode_hex("DEADBEEF"), Some(vec![0xDE, 0xAD, 0xBE, 0xEF]));
+        assert_eq!(decode_hex(""), Some(vec![]));
+    }
+
+    #[test]
+    fn decode_hex_rejects_odd_length() {
+        assert_eq!(decode_hex("ABC"), None);
+    }
+
+    #[test]
+    fn decode_hex_rejects_non_hex_digits() {
+        assert_eq!(decode_hex("ZZ"), None);
+        assert_eq!(decode_hex("GG"), None);
+    }
+}
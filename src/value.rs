@@ -0,0 +1,49 @@
+use core::fmt;
+
+use crate::alloc_prelude::{Box, String, Vec};
+
+/// A single decoded value from a `settings.bin` entry.
+#[derive(Debug, PartialEq)]
+pub enum AddressedValue {
+    Bool(bool),
+    Int(u32),
+    Long(u64),
+    Float(f32),
+    String(Option<Box<String>>),
+    Binary(Option<Box<Vec<u8>>>)
+}
+
+impl fmt::Display for AddressedValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AddressedValue::Bool(val) => write!(f, "{}", val),
+            AddressedValue::Int(val) => write!(f, "{}", val),
+            AddressedValue::Long(val) => write!(f, "{}", val),
+            AddressedValue::Float(val) => write!(f, "{}", val),
+            // Every constructor in this crate fills `Some(..)`, but the
+            // field is publicly constructible, so a caller-built `None`
+            // still has to render rather than panic.
+            AddressedValue::String(val) => match val {
+                Some(v) => write!(f, "\"{}\"", v),
+                None => write!(f, "null")
+            },
+            AddressedValue::Binary(val) => match val {
+                Some(v) => write!(f, "{:X?}", v),
+                None => write!(f, "null")
+            }
+        }
+    }
+}
+
+/// A value together with the byte offset in the file it was read from.
+#[derive(Debug, PartialEq)]
+pub struct SettingsItem {
+    pub address: usize,
+    pub value: AddressedValue
+}
+
+impl fmt::Display for SettingsItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}@[addr:{:X?}]", self.value, self.address)
+    }
+}